@@ -29,6 +29,7 @@ mod log;
 mod mul;
 mod pow;
 mod special;
+mod strict;
 mod string;
 mod support;
 mod uint_dyn;
@@ -42,14 +43,16 @@ pub use uint_dyn::UintDyn;
 pub use bit_vec::Bits;
 
 #[doc(inline)]
-pub use self::{base_convert::BaseConvertError, bytes::nbytes, string::ParseError};
+pub use self::{
+    base_convert::BaseConvertError,
+    bytes::nbytes,
+    strict::{OverflowError, Operation},
+    string::ParseError,
+};
 
 #[doc(inline)]
 pub use ruint_macro::uint;
 
-// TODO: Have a `struct OverflowError` and use `Result<Self, OverflowError>`
-// instead of `Option<Self>`.
-
 #[cfg(all(has_generic_const_exprs, feature = "generic_const_exprs"))]
 pub mod nightly {
     //! Extra features that are nightly only.
@@ -182,6 +185,35 @@ impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
         Self::from_limbs(limbs)
     }
 
+    /// Construct a new integer from little-endian a slice of limbs, checking
+    /// for overflow.
+    ///
+    /// Unlike [`Self::from_limbs_slice`] this does not panic on over-sized or
+    /// over-long input. Slices shorter than `LIMBS` are zero-extended, slices
+    /// longer than `LIMBS` are accepted only when the excess limbs are all
+    /// zero, and the most significant limb is validated against the bit-mask.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ToLimbsError`] when the slice contains non-zero limbs beyond
+    /// `LIMBS`, or when the most significant limb has bits set above `BITS`.
+    pub fn try_from_limbs_slice(slice: &[u64]) -> Result<Self, ToLimbsError> {
+        let mut limbs = [0; LIMBS];
+        if slice.len() <= LIMBS {
+            limbs[..slice.len()].copy_from_slice(slice);
+        } else {
+            let (head, tail) = slice.split_at(LIMBS);
+            if tail.iter().any(|&limb| limb != 0) {
+                return Err(ToLimbsError::NonZeroExcess);
+            }
+            limbs.copy_from_slice(head);
+        }
+        if BITS > 0 && limbs[LIMBS - 1] > Self::MASK {
+            return Err(ToLimbsError::Overflow);
+        }
+        Ok(Self { limbs })
+    }
+
     const fn assert_valid() {
         // TODO: Replace with `assert_eq!` when it is made `const`.
         // Blocked on Rust, not issue known.
@@ -198,6 +230,30 @@ impl<const BITS: usize, const LIMBS: usize> Default for Uint<BITS, LIMBS> {
     }
 }
 
+/// Error from [`Uint::try_from_limbs_slice`] when a limb slice can not be
+/// represented by the target [`Uint`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ToLimbsError {
+    /// The slice is longer than `LIMBS` and one of the excess limbs is
+    /// non-zero, so the value does not fit.
+    NonZeroExcess,
+
+    /// The most significant limb has bits set above `BITS`.
+    Overflow,
+}
+
+impl core::fmt::Display for ToLimbsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NonZeroExcess => f.write_str("non-zero limb beyond the width of the Uint"),
+            Self::Overflow => f.write_str("value too large for the bit-size of the Uint"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ToLimbsError {}
+
 /// Number of `u64` limbs required to represent the given number of bits.
 /// This needs to be public because it is used in the `Uint` type.
 #[must_use]
@@ -244,6 +300,27 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_try_from_limbs_slice() {
+        assert_eq!(Uint::<64, 1>::try_from_limbs_slice(&[]), Ok(Uint::ZERO));
+        assert_eq!(
+            Uint::<128, 2>::try_from_limbs_slice(&[1]),
+            Ok(Uint::from_limbs([1, 0]))
+        );
+        assert_eq!(
+            Uint::<64, 1>::try_from_limbs_slice(&[1, 0]),
+            Ok(Uint::from_limbs([1]))
+        );
+        assert_eq!(
+            Uint::<64, 1>::try_from_limbs_slice(&[1, 1]),
+            Err(ToLimbsError::NonZeroExcess)
+        );
+        assert_eq!(
+            Uint::<1, 1>::try_from_limbs_slice(&[2]),
+            Err(ToLimbsError::Overflow)
+        );
+    }
+
     #[test]
     fn test_constants() {
         const_for!(BITS in SIZES {
@@ -0,0 +1,192 @@
+//! Strict arithmetic that reports overflow through a typed [`OverflowError`].
+//!
+//! The default operators on [`Uint`] wrap (see the type-level documentation),
+//! which makes unsigned underflow easy to miss: a bounds computation like
+//! `len > PAGE_SIZE - 2 - size` silently wraps to a huge value and defeats the
+//! check. The `strict_*` family returns `Err(OverflowError)` instead, and the
+//! `strict_*_or_panic` variants panic unconditionally — even in release builds.
+
+use crate::Uint;
+use core::fmt;
+
+/// The arithmetic operation that overflowed, carried by [`OverflowError`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[allow(missing_docs)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Shl,
+}
+
+impl Operation {
+    const fn verb(self) -> &'static str {
+        match self {
+            Self::Add => "addition",
+            Self::Sub => "subtraction",
+            Self::Mul => "multiplication",
+            Self::Shl => "left shift",
+        }
+    }
+}
+
+/// Error for strict arithmetic operations that overflowed or underflowed the
+/// `BITS`-wide range of a [`Uint`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OverflowError {
+    operation: Operation,
+    bits: usize,
+}
+
+impl OverflowError {
+    #[must_use]
+    const fn new(operation: Operation, bits: usize) -> Self {
+        Self { operation, bits }
+    }
+
+    /// The operation that overflowed.
+    #[must_use]
+    pub const fn operation(self) -> Operation {
+        self.operation
+    }
+
+    /// The bit-width of the operands involved.
+    #[must_use]
+    pub const fn bits(self) -> usize {
+        self.bits
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} overflowed Uint<{}>",
+            self.operation.verb(),
+            self.bits
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OverflowError {}
+
+impl<const BITS: usize, const LIMBS: usize> Uint<BITS, LIMBS> {
+    /// Strict addition. Returns `Err(OverflowError)` instead of wrapping when
+    /// the result does not fit in `BITS` bits.
+    #[inline]
+    pub fn strict_add(self, rhs: Self) -> Result<Self, OverflowError> {
+        match self.overflowing_add(rhs) {
+            (value, false) => Ok(value),
+            (_, true) => Err(OverflowError::new(Operation::Add, BITS)),
+        }
+    }
+
+    /// Strict subtraction. Returns `Err(OverflowError)` on underflow instead of
+    /// wrapping to a large value.
+    #[inline]
+    pub fn strict_sub(self, rhs: Self) -> Result<Self, OverflowError> {
+        match self.overflowing_sub(rhs) {
+            (value, false) => Ok(value),
+            (_, true) => Err(OverflowError::new(Operation::Sub, BITS)),
+        }
+    }
+
+    /// Strict multiplication. Returns `Err(OverflowError)` instead of wrapping
+    /// when the result does not fit in `BITS` bits.
+    #[inline]
+    pub fn strict_mul(self, rhs: Self) -> Result<Self, OverflowError> {
+        match self.overflowing_mul(rhs) {
+            (value, false) => Ok(value),
+            (_, true) => Err(OverflowError::new(Operation::Mul, BITS)),
+        }
+    }
+
+    /// Strict left shift. Returns `Err(OverflowError)` when non-zero bits are
+    /// shifted out of the `BITS`-wide range.
+    #[inline]
+    pub fn strict_shl(self, rhs: usize) -> Result<Self, OverflowError> {
+        match self.overflowing_shl(rhs) {
+            (value, false) => Ok(value),
+            (_, true) => Err(OverflowError::new(Operation::Shl, BITS)),
+        }
+    }
+
+    /// Strict addition that panics on overflow, in debug *and* release builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in `BITS` bits.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn strict_add_or_panic(self, rhs: Self) -> Self {
+        self.strict_add(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Strict subtraction that panics on underflow, in debug *and* release
+    /// builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs > self`.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn strict_sub_or_panic(self, rhs: Self) -> Self {
+        self.strict_sub(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Strict multiplication that panics on overflow, in debug *and* release
+    /// builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in `BITS` bits.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn strict_mul_or_panic(self, rhs: Self) -> Self {
+        self.strict_mul(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Strict left shift that panics when bits are shifted out, in debug *and*
+    /// release builds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if non-zero bits are shifted out of the `BITS`-wide range.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn strict_shl_or_panic(self, rhs: usize) -> Self {
+        self.strict_shl(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aliases::U64;
+
+    #[test]
+    fn test_strict_sub_underflow() {
+        assert_eq!(
+            U64::from(1).strict_sub(U64::from(2)),
+            Err(OverflowError::new(Operation::Sub, 64))
+        );
+        assert_eq!(U64::from(3).strict_sub(U64::from(2)), Ok(U64::from(1)));
+    }
+
+    #[test]
+    fn test_strict_add_overflow() {
+        assert!(U64::MAX.strict_add(U64::from(1)).is_err());
+        assert_eq!(U64::from(1).strict_add(U64::from(1)), Ok(U64::from(2)));
+    }
+
+    #[test]
+    #[should_panic = "subtraction overflowed"]
+    fn test_strict_sub_or_panic() {
+        let _ = U64::ZERO.strict_sub_or_panic(U64::from(1));
+    }
+}
@@ -0,0 +1,116 @@
+//! Support for the [`borsh`](https://crates.io/crates/borsh) crate.
+//!
+//! A [`Uint`] is encoded as a fixed-length little-endian byte array of
+//! [`nbytes(BITS)`][crate::nbytes] bytes, reusing the same byte machinery as
+//! [`Uint::to_le_bytes_vec`] and [`Uint::try_from_le_slice`]. The on-wire form
+//! is therefore width-exact and endian-stable, and deserialization rejects
+//! buffers that set bits above `BITS` instead of silently masking or panicking.
+
+#![cfg(feature = "borsh")]
+#![cfg_attr(docsrs, doc(cfg(feature = "borsh")))]
+
+use crate::{nbytes, Bits, Uint};
+use borsh::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    schema::{Declaration, Definition},
+    BorshDeserialize, BorshSchema, BorshSerialize,
+};
+use std::collections::BTreeMap;
+
+impl<const BITS: usize, const LIMBS: usize> BorshSerialize for Uint<BITS, LIMBS> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.to_le_bytes_vec())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshDeserialize for Uint<BITS, LIMBS> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = vec![0; nbytes(BITS)];
+        reader.read_exact(&mut bytes)?;
+        Self::try_from_le_slice(&bytes).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "value has bits set above the Uint bit-size",
+            )
+        })
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshSchema for Uint<BITS, LIMBS> {
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let length = nbytes(BITS) as u64;
+        let definition = Definition::Sequence {
+            length_width: 0,
+            length_range: length..=length,
+            elements: u8::declaration(),
+        };
+        borsh::schema::add_definition(Self::declaration(), definition, definitions);
+        u8::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!("Uint<{BITS}, {LIMBS}>")
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshSerialize for Bits<BITS, LIMBS> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.as_uint().serialize(writer)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshDeserialize for Bits<BITS, LIMBS> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Uint::deserialize_reader(reader).map(Self::from)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> BorshSchema for Bits<BITS, LIMBS> {
+    fn add_definitions_recursively(definitions: &mut BTreeMap<Declaration, Definition>) {
+        let length = nbytes(BITS) as u64;
+        let definition = Definition::Sequence {
+            length_width: 0,
+            length_range: length..=length,
+            elements: u8::declaration(),
+        };
+        borsh::schema::add_definition(Self::declaration(), definition, definitions);
+        u8::add_definitions_recursively(definitions);
+    }
+
+    fn declaration() -> Declaration {
+        format!("Bits<{BITS}, {LIMBS}>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{const_for, nlimbs};
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_roundtrip() {
+        const_for!(BITS in SIZES {
+            const LIMBS: usize = nlimbs(BITS);
+            type U = Uint<BITS, LIMBS>;
+            proptest!(|(value: U)| {
+                let serialized = borsh::to_vec(&value).unwrap();
+                assert_eq!(serialized.len(), nbytes(BITS));
+                let deserialized = borsh::from_slice::<U>(&serialized).unwrap();
+                assert_eq!(value, deserialized);
+            });
+        });
+    }
+
+    #[test]
+    fn test_reject_out_of_range() {
+        // A high byte with bits set above `BITS` must be rejected rather than
+        // silently masked.
+        let bytes = [u8::MAX; nbytes(4)];
+        assert!(borsh::from_slice::<Uint<4, 1>>(&bytes).is_err());
+    }
+}
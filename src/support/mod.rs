@@ -0,0 +1,10 @@
+//! Support for external crates.
+//!
+//! Each submodule implements third-party traits for [`crate::Uint`] (and, in
+//! the `borsh` module, for [`crate::Bits`]), gated behind a matching Cargo
+//! feature so that the dependency is only pulled in when requested.
+
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "num-traits")]
+mod num_traits;
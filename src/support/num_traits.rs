@@ -0,0 +1,339 @@
+//! Support for the [`num-traits`](https://crates.io/crates/num-traits) crate.
+//!
+//! The implementations delegate to the inherent methods on [`Uint`] so that
+//! values can be used directly in generic numeric code written against
+//! `num-traits` bounds. Since [`Uint`] is purely integral there is no
+//! dependency on `libm`, and the feature stays `no_std`-compatible.
+
+#![cfg(feature = "num-traits")]
+#![cfg_attr(docsrs, doc(cfg(feature = "num-traits")))]
+
+use crate::{ParseError, Uint};
+use num_traits::{
+    bounds::Bounded,
+    ops::{
+        checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub},
+        saturating::{Saturating, SaturatingAdd, SaturatingMul, SaturatingSub},
+        wrapping::{WrappingAdd, WrappingMul, WrappingShl, WrappingShr, WrappingSub},
+    },
+    cast::NumCast,
+    identities::{One, Zero},
+    int::PrimInt,
+    ops::checked::{CheckedShl, CheckedShr},
+    Num, ToPrimitive, Unsigned,
+};
+
+impl<const BITS: usize, const LIMBS: usize> Zero for Uint<BITS, LIMBS> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self == &Self::ZERO
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> One for Uint<BITS, LIMBS> {
+    #[inline]
+    fn one() -> Self {
+        Self::from(1)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Bounded for Uint<BITS, LIMBS> {
+    #[inline]
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Num for Uint<BITS, LIMBS> {
+    type FromStrRadixErr = ParseError;
+
+    #[inline]
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Self::from_str_radix(str, u64::from(radix))
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Unsigned for Uint<BITS, LIMBS> {}
+
+impl<const BITS: usize, const LIMBS: usize> CheckedAdd for Uint<BITS, LIMBS> {
+    #[inline]
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Self::checked_add(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> CheckedSub for Uint<BITS, LIMBS> {
+    #[inline]
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Self::checked_sub(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> CheckedMul for Uint<BITS, LIMBS> {
+    #[inline]
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Self::checked_mul(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> CheckedDiv for Uint<BITS, LIMBS> {
+    #[inline]
+    fn checked_div(&self, other: &Self) -> Option<Self> {
+        Self::checked_div(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> CheckedRem for Uint<BITS, LIMBS> {
+    #[inline]
+    fn checked_rem(&self, other: &Self) -> Option<Self> {
+        Self::checked_rem(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> CheckedShl for Uint<BITS, LIMBS> {
+    #[inline]
+    fn checked_shl(&self, rhs: u32) -> Option<Self> {
+        Self::checked_shl(*self, rhs as usize)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> CheckedShr for Uint<BITS, LIMBS> {
+    #[inline]
+    fn checked_shr(&self, rhs: u32) -> Option<Self> {
+        Self::checked_shr(*self, rhs as usize)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> WrappingAdd for Uint<BITS, LIMBS> {
+    #[inline]
+    fn wrapping_add(&self, other: &Self) -> Self {
+        Self::wrapping_add(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> WrappingSub for Uint<BITS, LIMBS> {
+    #[inline]
+    fn wrapping_sub(&self, other: &Self) -> Self {
+        Self::wrapping_sub(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> WrappingMul for Uint<BITS, LIMBS> {
+    #[inline]
+    fn wrapping_mul(&self, other: &Self) -> Self {
+        Self::wrapping_mul(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> WrappingShl for Uint<BITS, LIMBS> {
+    #[inline]
+    fn wrapping_shl(&self, rhs: u32) -> Self {
+        Self::wrapping_shl(*self, rhs as usize)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> WrappingShr for Uint<BITS, LIMBS> {
+    #[inline]
+    fn wrapping_shr(&self, rhs: u32) -> Self {
+        Self::wrapping_shr(*self, rhs as usize)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> SaturatingAdd for Uint<BITS, LIMBS> {
+    #[inline]
+    fn saturating_add(&self, other: &Self) -> Self {
+        Self::saturating_add(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> SaturatingSub for Uint<BITS, LIMBS> {
+    #[inline]
+    fn saturating_sub(&self, other: &Self) -> Self {
+        Self::saturating_sub(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> SaturatingMul for Uint<BITS, LIMBS> {
+    #[inline]
+    fn saturating_mul(&self, other: &Self) -> Self {
+        Self::saturating_mul(*self, *other)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> ToPrimitive for Uint<BITS, LIMBS> {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(*self).ok()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(*self).ok()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> num_traits::FromPrimitive for Uint<BITS, LIMBS> {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Self::try_from(n).ok()
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> Saturating for Uint<BITS, LIMBS> {
+    #[inline]
+    fn saturating_add(self, v: Self) -> Self {
+        Self::saturating_add(self, v)
+    }
+
+    #[inline]
+    fn saturating_sub(self, v: Self) -> Self {
+        Self::saturating_sub(self, v)
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> NumCast for Uint<BITS, LIMBS> {
+    #[inline]
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        n.to_u128().and_then(|n| Self::try_from(n).ok())
+    }
+}
+
+impl<const BITS: usize, const LIMBS: usize> PrimInt for Uint<BITS, LIMBS> {
+    #[inline]
+    fn count_ones(self) -> u32 {
+        Self::count_ones(&self) as u32
+    }
+
+    #[inline]
+    fn count_zeros(self) -> u32 {
+        Self::count_zeros(&self) as u32
+    }
+
+    #[inline]
+    fn leading_zeros(self) -> u32 {
+        Self::leading_zeros(&self) as u32
+    }
+
+    #[inline]
+    fn trailing_zeros(self) -> u32 {
+        Self::trailing_zeros(&self) as u32
+    }
+
+    #[inline]
+    fn leading_ones(self) -> u32 {
+        Self::leading_ones(&self) as u32
+    }
+
+    #[inline]
+    fn trailing_ones(self) -> u32 {
+        Self::trailing_ones(&self) as u32
+    }
+
+    #[inline]
+    fn rotate_left(self, n: u32) -> Self {
+        Self::rotate_left(self, n as usize)
+    }
+
+    #[inline]
+    fn rotate_right(self, n: u32) -> Self {
+        Self::rotate_right(self, n as usize)
+    }
+
+    #[inline]
+    fn signed_shl(self, n: u32) -> Self {
+        self << n as usize
+    }
+
+    #[inline]
+    fn signed_shr(self, n: u32) -> Self {
+        self >> n as usize
+    }
+
+    #[inline]
+    fn unsigned_shl(self, n: u32) -> Self {
+        self << n as usize
+    }
+
+    #[inline]
+    fn unsigned_shr(self, n: u32) -> Self {
+        self >> n as usize
+    }
+
+    #[inline]
+    fn swap_bytes(self) -> Self {
+        Self::swap_bytes(self)
+    }
+
+    #[inline]
+    fn from_be(x: Self) -> Self {
+        Self::from_be(x)
+    }
+
+    #[inline]
+    fn from_le(x: Self) -> Self {
+        Self::from_le(x)
+    }
+
+    #[inline]
+    fn to_be(self) -> Self {
+        Self::to_be(self)
+    }
+
+    #[inline]
+    fn to_le(self) -> Self {
+        Self::to_le(self)
+    }
+
+    #[inline]
+    fn pow(self, mut exp: u32) -> Self {
+        // Square-and-multiply over the `u32` exponent so a large `exp` does not
+        // have to fit in `Self` (e.g. `1.pow(5)` on a 2-bit `Uint`).
+        let mut base = self;
+        let mut acc = Self::from(1);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.wrapping_mul(base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.wrapping_mul(base);
+            }
+        }
+        acc
+    }
+}